@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
+use js_sys::Date;
 use serde::{Deserialize, Serialize};
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlElement, HtmlInputElement};
 use yew::prelude::*;
 use yew_agent::{Bridge, Bridged};
 use crate::services::event_bus::EventBus;
@@ -8,12 +11,35 @@ use crate::{User, services::websocket::WebsocketService};
 pub enum Msg {
     HandleMsg(String),
     SubmitMessage,
+    ToggleTheme,
 }
 
-#[derive(Deserialize)]
+const THEME_STORAGE_KEY: &str = "yewchat_theme";
+
+#[derive(Deserialize, Clone)]
 struct MessageData {
     from: String,
     message: String,
+    #[serde(default)]
+    timestamp: Option<i64>,
+    #[serde(skip)]
+    id: Option<String>,
+    #[serde(skip)]
+    streaming: bool,
+}
+
+#[derive(Deserialize)]
+struct TypingData {
+    id: String,
+    from: String,
+}
+
+#[derive(Deserialize)]
+struct MessageChunkData {
+    id: String,
+    from: String,
+    delta: String,
+    done: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -22,6 +48,8 @@ pub enum MsgTypes {
     Users,
     Register,
     Message,
+    Typing,
+    MessageChunk,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -30,6 +58,8 @@ struct WebSocketMessage {
     message_type: MsgTypes,
     data_array: Option<Vec<String>>,
     data: Option<String>,
+    #[serde(default)]
+    timestamp: Option<i64>,
 }
 
 #[derive(Clone)]
@@ -38,14 +68,240 @@ struct UserProfile {
     avatar: String,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+enum Fragment {
+    Text(String),
+    Url(String),
+    Mention(String),
+    Image(String),
+}
+
+fn is_image_url(url: &str) -> bool {
+    [".gif", ".png", ".jpg", ".jpeg", ".webp"]
+        .iter()
+        .any(|ext| url.ends_with(ext))
+}
+
+fn is_allowed_link_scheme(url: &str) -> bool {
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+/// Splits a message into fragments, classifying each whitespace-separated
+/// token as a URL, image, mention, or plain text. Consecutive `Text`
+/// fragments (and the whitespace between tokens) are coalesced back into a
+/// single fragment so that concatenating all fragments reproduces `message`.
+fn parse_fragments(message: &str, users: &[UserProfile]) -> Vec<Fragment> {
+    let mut fragments: Vec<Fragment> = vec![];
+    let mut rest = message;
+
+    while !rest.is_empty() {
+        let ws_len = rest
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(rest.len());
+        let (ws, after_ws) = rest.split_at(ws_len);
+
+        let token_len = after_ws
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(after_ws.len());
+        let (token, remainder) = after_ws.split_at(token_len);
+
+        if !ws.is_empty() {
+            push_text(&mut fragments, ws);
+        }
+
+        if token.is_empty() {
+            rest = remainder;
+            continue;
+        }
+
+        let fragment = if token.starts_with("http://") || token.starts_with("https://") {
+            if is_image_url(token) {
+                Fragment::Image(token.to_string())
+            } else {
+                Fragment::Url(token.to_string())
+            }
+        } else if let Some(name) = token.strip_prefix('@') {
+            if users.iter().any(|u| u.name == name) {
+                Fragment::Mention(token.to_string())
+            } else {
+                Fragment::Text(token.to_string())
+            }
+        } else {
+            Fragment::Text(token.to_string())
+        };
+
+        match fragment {
+            Fragment::Text(t) => push_text(&mut fragments, &t),
+            other => fragments.push(other),
+        }
+
+        rest = remainder;
+    }
+
+    fragments
+}
+
+fn push_text(fragments: &mut Vec<Fragment>, text: &str) {
+    if let Some(Fragment::Text(last)) = fragments.last_mut() {
+        last.push_str(text);
+    } else {
+        fragments.push(Fragment::Text(text.to_string()));
+    }
+}
+
+/// Renders a message's timestamp as a short relative label. Messages from
+/// servers that don't yet send a `timestamp` fall back to the old copy.
+fn format_timestamp(timestamp: Option<i64>) -> String {
+    let Some(sent_at) = timestamp else {
+        return "just now".to_string();
+    };
+
+    let elapsed_ms = Date::now() as i64 - sent_at;
+    if elapsed_ms < 60_000 {
+        "just now".to_string()
+    } else if elapsed_ms < 60 * 60_000 {
+        format!("{}m ago", elapsed_ms / 60_000)
+    } else {
+        let date = Date::new(&wasm_bindgen::JsValue::from_f64(sent_at as f64));
+        format!("{:02}:{:02}", date.get_hours(), date.get_minutes())
+    }
+}
+
+struct MarkdownFrame {
+    href: Option<String>,
+    in_code_block: bool,
+    children: Vec<Html>,
+}
+
+fn render_fragment(fragment: Fragment) -> Html {
+    match fragment {
+        Fragment::Text(text) => html! { {text} },
+        Fragment::Url(url) => html! {
+            <a href={url.clone()} target="_blank" rel="noopener noreferrer" class="text-blue-600 hover:text-blue-800 dark:text-blue-400 dark:hover:text-blue-300 underline break-all">{url}</a>
+        },
+        Fragment::Mention(name) => html! {
+            <span class="inline-block bg-blue-100 dark:bg-blue-900/60 text-blue-700 dark:text-blue-300 font-semibold px-2 py-0.5 rounded-full">{name}</span>
+        },
+        Fragment::Image(url) => html! {
+            <img class="rounded-xl shadow-md max-w-full h-auto hover:scale-105 transition-transform duration-300 my-1" src={url} alt="shared image" />
+        },
+    }
+}
+
+/// Renders a whole message as lightweight Markdown (bold, italic, inline
+/// code, fenced code blocks, links), then runs URL/mention/image fragment
+/// classification only over the plain-text runs the Markdown parser hands
+/// back. Running Markdown first (rather than splitting on whitespace before
+/// it sees the message) keeps code spans and fenced blocks intact — a token
+/// inside a code fence is never torn out into its own `Url`/`Mention`
+/// fragment. `pulldown_cmark` walks the text into a flat event stream which
+/// we fold into nested Yew nodes via a stack; message text reaches the DOM
+/// only through `Text`/`Code` events, and raw `Html`/`InlineHtml` events are
+/// dropped, so raw HTML in a message can't inject markup.
+fn render_markdown(text: &str, users: &[UserProfile]) -> Html {
+    use pulldown_cmark::{Event, Options, Parser, Tag};
+
+    let parser = Parser::new_ext(text, Options::ENABLE_STRIKETHROUGH);
+    let mut stack: Vec<MarkdownFrame> = vec![MarkdownFrame { href: None, in_code_block: false, children: vec![] }];
+
+    for event in parser {
+        match event {
+            Event::Start(tag) => {
+                let href = match &tag {
+                    Tag::Link { dest_url, .. } => Some(dest_url.to_string()),
+                    _ => None,
+                };
+                let parent_in_code_block = stack.last().map(|f| f.in_code_block).unwrap_or(false);
+                let in_code_block = parent_in_code_block || matches!(tag, Tag::CodeBlock(_));
+                stack.push(MarkdownFrame { href, in_code_block, children: vec![] });
+            }
+            Event::End(tag_end) => {
+                let frame = stack.pop().unwrap_or(MarkdownFrame { href: None, in_code_block: false, children: vec![] });
+                let node = wrap_markdown_tag(tag_end, frame.href, frame.children);
+                stack.last_mut().expect("root frame always present").children.push(node);
+            }
+            Event::Text(text) => {
+                let frame = stack.last_mut().expect("root frame always present");
+                if frame.in_code_block {
+                    frame.children.push(html! { {text.to_string()} });
+                } else {
+                    for fragment in parse_fragments(&text, users) {
+                        frame.children.push(render_fragment(fragment));
+                    }
+                }
+            }
+            Event::Code(code) => {
+                stack.last_mut().expect("root frame always present").children.push(html! {
+                    <code class="px-1.5 py-0.5 rounded bg-slate-100 dark:bg-slate-700 text-pink-600 dark:text-pink-300 font-mono text-[0.9em]">{code.to_string()}</code>
+                });
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                stack.last_mut().expect("root frame always present").children.push(html! { <br/> });
+            }
+            _ => {}
+        }
+    }
+
+    stack.pop().map(|frame| frame.children).unwrap_or_default().into_iter().collect::<Html>()
+}
+
+fn wrap_markdown_tag(tag: pulldown_cmark::TagEnd, href: Option<String>, children: Vec<Html>) -> Html {
+    use pulldown_cmark::TagEnd;
+
+    match tag {
+        TagEnd::Emphasis => html! { <em>{ for children }</em> },
+        TagEnd::Strong => html! { <strong>{ for children }</strong> },
+        TagEnd::Strikethrough => html! { <del>{ for children }</del> },
+        TagEnd::CodeBlock => html! {
+            <pre class="my-2 p-3 rounded-xl bg-slate-900 dark:bg-black/60 text-slate-100 shadow-md overflow-x-auto text-sm font-mono"><code>{ for children }</code></pre>
+        },
+        TagEnd::Link => {
+            match href.filter(|href| is_allowed_link_scheme(href)) {
+                Some(href) => html! {
+                    <a href={href} target="_blank" rel="noopener noreferrer" class="text-blue-600 hover:text-blue-800 dark:text-blue-400 dark:hover:text-blue-300 underline">{ for children }</a>
+                },
+                None => html! { <>{ for children }</> },
+            }
+        }
+        _ => html! { <>{ for children }</> },
+    }
+}
+
 pub struct Chat {
     users: Vec<UserProfile>,
     chat_input: NodeRef,
+    messages_container: NodeRef,
     wss: WebsocketService,
     messages: Vec<MessageData>,
+    streaming_messages: HashMap<String, usize>,
+    last_rendered_message_count: usize,
+    dark_theme: bool,
     _producer: Box<dyn Bridge<EventBus>>,
 }
 
+fn stored_theme_is_dark() -> bool {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(THEME_STORAGE_KEY).ok().flatten())
+        .map(|value| value == "dark")
+        .unwrap_or(false)
+}
+
+fn apply_theme(dark: bool) {
+    if let Some(root) = web_sys::window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+        if dark {
+            let _ = root.class_list().add_1("dark");
+        } else {
+            let _ = root.class_list().remove_1("dark");
+        }
+    }
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.set_item(THEME_STORAGE_KEY, if dark { "dark" } else { "light" });
+    }
+}
+
 impl Component for Chat {
     type Message = Msg;
     type Properties = ();
@@ -62,6 +318,7 @@ impl Component for Chat {
             message_type: MsgTypes::Register,
             data: Some(username.to_string()),
             data_array: None,
+            timestamp: None,
         };
 
         if let Ok(_) = wss
@@ -72,11 +329,18 @@ impl Component for Chat {
             log::debug!("message sent successfully");
         }
 
+        let dark_theme = stored_theme_is_dark();
+        apply_theme(dark_theme);
+
         Self {
             users: vec![],
             messages: vec![],
+            streaming_messages: HashMap::new(),
             chat_input: NodeRef::default(),
+            messages_container: NodeRef::default(),
             wss,
+            last_rendered_message_count: 0,
+            dark_theme,
             _producer: EventBus::bridge(ctx.link().callback(Msg::HandleMsg)),
         }
     }
@@ -102,11 +366,57 @@ impl Component for Chat {
                         return true;
                     }
                     MsgTypes::Message => {
-                        let message_data: MessageData =
+                        let mut message_data: MessageData =
                             serde_json::from_str(&msg.data.unwrap()).unwrap();
+                        if message_data.timestamp.is_none() {
+                            message_data.timestamp = msg.timestamp;
+                        }
                         self.messages.push(message_data);
                         return true;
                     }
+                    MsgTypes::Typing => {
+                        let typing_data: TypingData =
+                            serde_json::from_str(&msg.data.unwrap()).unwrap();
+                        if !self.streaming_messages.contains_key(&typing_data.id) {
+                            self.messages.push(MessageData {
+                                from: typing_data.from,
+                                message: String::new(),
+                                timestamp: None,
+                                id: Some(typing_data.id.clone()),
+                                streaming: true,
+                            });
+                            self.streaming_messages
+                                .insert(typing_data.id, self.messages.len() - 1);
+                        }
+                        return true;
+                    }
+                    MsgTypes::MessageChunk => {
+                        let chunk: MessageChunkData =
+                            serde_json::from_str(&msg.data.unwrap()).unwrap();
+                        let index = match self.streaming_messages.get(&chunk.id) {
+                            Some(&index) => index,
+                            None => {
+                                self.messages.push(MessageData {
+                                    from: chunk.from.clone(),
+                                    message: String::new(),
+                                    timestamp: None,
+                                    id: Some(chunk.id.clone()),
+                                    streaming: true,
+                                });
+                                let index = self.messages.len() - 1;
+                                self.streaming_messages.insert(chunk.id.clone(), index);
+                                index
+                            }
+                        };
+                        if let Some(message_data) = self.messages.get_mut(index) {
+                            message_data.message.push_str(&chunk.delta);
+                            if chunk.done {
+                                message_data.streaming = false;
+                                self.streaming_messages.remove(&chunk.id);
+                            }
+                        }
+                        return true;
+                    }
                     _ => {
                         return false;
                     }
@@ -120,6 +430,7 @@ impl Component for Chat {
                         message_type: MsgTypes::Message,
                         data: Some(input.value()),
                         data_array: None,
+                        timestamp: Some(Date::now() as i64),
                     };
                     if let Err(e) = self
                         .wss
@@ -133,17 +444,35 @@ impl Component for Chat {
                 };
                 false
             }
+            Msg::ToggleTheme => {
+                self.dark_theme = !self.dark_theme;
+                apply_theme(self.dark_theme);
+                true
+            }
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if self.messages.len() <= self.last_rendered_message_count {
+            self.last_rendered_message_count = self.messages.len();
+            return;
+        }
+        self.last_rendered_message_count = self.messages.len();
+
+        if let Some(container) = self.messages_container.cast::<HtmlElement>() {
+            container.set_scroll_top(container.scroll_height());
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let submit = ctx.link().callback(|_| Msg::SubmitMessage);
-        
+        let toggle_theme = ctx.link().callback(|_| Msg::ToggleTheme);
+
         html! {
-            <div class="w-full h-screen flex bg-gradient-to-br from-blue-50 via-indigo-50 to-slate-50 overflow-hidden">
+            <div class="w-full h-screen flex bg-gradient-to-br from-blue-50 via-indigo-50 to-slate-50 dark:from-slate-900 dark:via-slate-900 dark:to-slate-950 overflow-hidden">
 
-                <div class="hidden md:flex md:flex-none md:w-64 lg:w-72 h-full bg-white/95 backdrop-blur-xl border-r border-blue-200/70 shadow-xl flex-col">
-                    <div class="flex-none text-xl md:text-2xl p-4 md:p-5 font-bold border-b border-blue-200/50 text-transparent bg-clip-text bg-gradient-to-r from-blue-600 to-indigo-600">
+                <div class="hidden md:flex md:flex-none md:w-64 lg:w-72 h-full bg-white/95 dark:bg-slate-800/95 backdrop-blur-xl border-r border-blue-200/70 dark:border-slate-700/70 shadow-xl flex-col">
+                    <div class="flex-none text-xl md:text-2xl p-4 md:p-5 font-bold border-b border-blue-200/50 dark:border-slate-700/50 text-transparent bg-clip-text bg-gradient-to-r from-blue-600 to-indigo-600">
                         <div class="flex items-center space-x-2">
                             <div class="w-2 h-2 bg-emerald-400 rounded-full animate-pulse shadow-sm"></div>
                             {"Users Online"}
@@ -184,40 +513,50 @@ impl Component for Chat {
 
                 <div class="flex-1 h-full flex flex-col min-w-0">
 
-                    <div class="flex-none w-full h-16 md:h-18 border-b border-blue-200/60 flex items-center px-4 md:px-6 bg-white/95 backdrop-blur-xl shadow-sm">
+                    <div class="flex-none w-full h-16 md:h-18 border-b border-blue-200/60 dark:border-slate-700/60 flex items-center px-4 md:px-6 bg-white/95 dark:bg-slate-800/95 backdrop-blur-xl shadow-sm">
                         <div class="flex items-center space-x-3">
                             <div class="text-2xl md:text-3xl">{"ðŸ’¬"}</div>
                             <div class="text-xl md:text-2xl font-bold text-transparent bg-clip-text bg-gradient-to-r from-blue-600 to-indigo-600">
                                 {"Chat Hub"}
                             </div>
-                            <div class="hidden md:flex items-center text-sm text-blue-600 ml-4 bg-blue-50 px-3 py-1 rounded-full">
+                            <div class="hidden md:flex items-center text-sm text-blue-600 dark:text-blue-300 ml-4 bg-blue-50 dark:bg-slate-700 px-3 py-1 rounded-full">
                                 <div class="w-2 h-2 bg-emerald-400 rounded-full mr-2 animate-pulse"></div>
                                 {format!("{} online", self.users.len())}
                             </div>
                         </div>
 
-                        <button class="md:hidden ml-auto p-3 text-blue-600 hover:bg-blue-50 rounded-full transition-colors duration-200">
+                        <button
+                            onclick={toggle_theme}
+                            aria-label="Toggle dark mode"
+                            class="ml-auto p-3 text-blue-600 dark:text-blue-300 hover:bg-blue-50 dark:hover:bg-slate-700 rounded-full transition-colors duration-200"
+                        >
+                            { if self.dark_theme { "☀️" } else { "🌙" } }
+                        </button>
+
+                        <button class="md:hidden ml-2 p-3 text-blue-600 dark:text-blue-300 hover:bg-blue-50 dark:hover:bg-slate-700 rounded-full transition-colors duration-200">
                             <svg class="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path>
                             </svg>
                         </button>
                     </div>
 
-                    <div class="flex-1 w-full overflow-y-auto p-4 md:p-6 lg:p-8 space-y-4 md:space-y-6 bg-gradient-to-br from-blue-50/30 via-indigo-50/30 to-slate-50/50 scrollbar-thin scrollbar-thumb-blue-300 scrollbar-track-transparent">
+                    <div ref={self.messages_container.clone()} class="flex-1 w-full overflow-y-auto p-4 md:p-6 lg:p-8 space-y-4 md:space-y-6 bg-gradient-to-br from-blue-50/30 via-indigo-50/30 to-slate-50/50 dark:from-slate-900/40 dark:via-slate-900/40 dark:to-slate-950/50 scrollbar-thin scrollbar-thumb-blue-300 scrollbar-track-transparent">
                         {
                             self.messages.iter().enumerate().map(|(index, m)| {
-                                let user = self.users.iter().find(|u| u.name == m.from).unwrap();
+                                let avatar = self.users.iter().find(|u| u.name == m.from)
+                                    .map(|u| u.avatar.clone())
+                                    .unwrap_or_else(|| format!("https://avatars.dicebear.com/api/adventurer-neutral/{}.svg", m.from));
                                 let is_even = index % 2 == 0;
                                 let animation_delay = format!("animation-delay: {}ms", index * 150);
                                 
                                 html! {
                                     <div class="animate-fade-in-left" style={animation_delay}>
-                                        <div class={format!("flex items-end max-w-full sm:max-w-[85%] md:max-w-[75%] lg:max-w-[65%] {} rounded-2xl shadow-sm hover:shadow-md transition-all duration-300 border backdrop-blur-sm", 
-                                            if is_even { "bg-white/90 border-blue-200/50 hover:bg-white/95" } else { "bg-gradient-to-r from-blue-50/80 to-indigo-50/80 border-indigo-200/50 hover:from-blue-100/90 hover:to-indigo-100/90" })}>
+                                        <div class={format!("flex items-end max-w-full sm:max-w-[85%] md:max-w-[75%] lg:max-w-[65%] {} rounded-2xl shadow-sm hover:shadow-md transition-all duration-300 border backdrop-blur-sm",
+                                            if is_even { "bg-white/90 dark:bg-slate-800/90 border-blue-200/50 dark:border-slate-700/50 hover:bg-white/95 dark:hover:bg-slate-800" } else { "bg-gradient-to-r from-blue-50/80 to-indigo-50/80 dark:from-slate-800/80 dark:to-slate-700/80 border-indigo-200/50 dark:border-slate-600/50 hover:from-blue-100/90 hover:to-indigo-100/90 dark:hover:from-slate-800 dark:hover:to-slate-700" })}>
                                             <div class="flex-shrink-0">
                                                 <div class="relative">
-                                                    <img class="w-10 h-10 md:w-12 md:h-12 rounded-full m-3 md:m-4 object-cover border-2 border-blue-200 shadow-sm" 
-                                                        src={user.avatar.clone()} alt="avatar" />
+                                                    <img class="w-10 h-10 md:w-12 md:h-12 rounded-full m-3 md:m-4 object-cover border-2 border-blue-200 shadow-sm"
+                                                        src={avatar} alt="avatar" />
                                                     <div class="absolute bottom-2 right-2 w-3 h-3 bg-emerald-400 border-2 border-white rounded-full"></div>
                                                 </div>
                                             </div>
@@ -227,18 +566,31 @@ impl Component for Chat {
                                                         {m.from.clone()}
                                                     </div>
                                                     <div class="text-xs text-blue-400 font-medium">
-                                                        {"â€¢ just now"}
+                                                        {format!("• {}", format_timestamp(m.timestamp))}
                                                     </div>
                                                 </div>
-                                                <div class="text-sm md:text-base text-slate-700 leading-relaxed">
+                                                <div class="text-sm md:text-base text-slate-700 dark:text-slate-200 leading-relaxed break-words">
                                                     {
-                                                        if m.message.ends_with(".gif") {
+                                                        if m.streaming && m.message.is_empty() {
                                                             html! {
-                                                                <img class="rounded-xl shadow-md max-w-full h-auto hover:scale-105 transition-transform duration-300" src={m.message.clone()} alt="gif" />
+                                                                <span class="inline-flex space-x-1 py-1" aria-label="typing">
+                                                                    <span class="w-2 h-2 bg-blue-400 rounded-full animate-bounce"></span>
+                                                                    <span class="w-2 h-2 bg-blue-400 rounded-full animate-bounce" style="animation-delay: 150ms"></span>
+                                                                    <span class="w-2 h-2 bg-blue-400 rounded-full animate-bounce" style="animation-delay: 300ms"></span>
+                                                                </span>
                                                             }
                                                         } else {
                                                             html! {
-                                                                <div class="break-words">{m.message.clone()}</div>
+                                                                <>
+                                                                    { render_markdown(&m.message, &self.users) }
+                                                                    {
+                                                                        if m.streaming {
+                                                                            html! { <span class="inline-block w-1.5 h-4 ml-0.5 bg-blue-400 align-middle animate-pulse"></span> }
+                                                                        } else {
+                                                                            html! {}
+                                                                        }
+                                                                    }
+                                                                </>
                                                             }
                                                         }
                                                     }
@@ -252,14 +604,14 @@ impl Component for Chat {
                     </div>
 
                 
-                    <div class="flex-none w-full h-16 md:h-18 flex px-4 md:px-6 lg:px-8 py-3 md:py-4 items-center bg-white/95 backdrop-blur-xl border-t border-blue-200/60 shadow-sm">
+                    <div class="flex-none w-full h-16 md:h-18 flex px-4 md:px-6 lg:px-8 py-3 md:py-4 items-center bg-white/95 dark:bg-slate-800/95 backdrop-blur-xl border-t border-blue-200/60 dark:border-slate-700/60 shadow-sm">
                         <div class="flex-1 flex items-center space-x-3 md:space-x-4">
                             <div class="relative flex-1">
                                 <input 
                                     ref={self.chat_input.clone()} 
                                     type="text" 
                                     placeholder="Type your message..." 
-                                    class="w-full py-3 md:py-4 px-5 md:px-6 bg-gradient-to-r from-slate-50 to-blue-50 hover:from-white hover:to-blue-50 rounded-full outline-none text-sm md:text-base text-slate-800 placeholder-blue-400 focus:ring-2 focus:ring-blue-400 focus:bg-white transition-all duration-300 border border-blue-200/50 focus:border-blue-300 shadow-sm hover:shadow-md" 
+                                    class="w-full py-3 md:py-4 px-5 md:px-6 bg-gradient-to-r from-slate-50 to-blue-50 dark:from-slate-700 dark:to-slate-700 hover:from-white hover:to-blue-50 dark:hover:from-slate-600 dark:hover:to-slate-600 rounded-full outline-none text-sm md:text-base text-slate-800 dark:text-slate-100 placeholder-blue-400 dark:placeholder-slate-400 focus:ring-2 focus:ring-blue-400 focus:bg-white dark:focus:bg-slate-600 transition-all duration-300 border border-blue-200/50 dark:border-slate-600/50 focus:border-blue-300 shadow-sm hover:shadow-md" 
                                     name="message" 
                                     required=true 
                                 />